@@ -1,6 +1,6 @@
 import { Actor, HttpAgent } from '@dfinity/agent';
 import { IDL } from '@dfinity/candid';
-import type { Skill, SkillSearchResult, SkillAnalysis, AnalysisResult, Stats, UserProfile } from './types';
+import type { Skill, SkillSummary, SkillPage, SearchPage, SafetyVerdict, SkillSearchResult, SkillAnalysis, AnalysisResult, Stats, UserProfile } from './types';
 import { skillCache } from './cache';
 
 // ============================================================================
@@ -15,12 +15,6 @@ const LOCAL_HOST = 'http://127.0.0.1:4943';
 // IDL Factory (generated from .did)
 // ============================================================================
 
-const AnalysisModel = IDL.Variant({
-  Haiku: IDL.Null,
-  Sonnet: IDL.Null,
-  Opus: IDL.Null,
-});
-
 const RatingTopic = IDL.Variant({
   Quality: IDL.Null,
   Documentation: IDL.Null,
@@ -166,9 +160,57 @@ const SkillIDL = IDL.Record({
   source: IDL.Text,
 });
 
+const SafetyVerdictIDL = IDL.Variant({
+  Safe: IDL.Null,
+  Ok: IDL.Null,
+  Caution: IDL.Null,
+  Warning: IDL.Null,
+  Danger: IDL.Null,
+});
+
+const SkillSummaryIDL = IDL.Record({
+  id: IDL.Text,
+  name: IDL.Text,
+  owner: IDL.Text,
+  repo: IDL.Text,
+  stars: IDL.Nat32,
+  install_count: IDL.Nat64,
+  overall_rating: IDL.Opt(IDL.Float32),
+  verdict: IDL.Opt(SafetyVerdictIDL),
+  is_stale: IDL.Bool,
+  category: IDL.Opt(IDL.Text),
+  info_flags: IDL.Nat32,
+  warning_flags: IDL.Nat32,
+  critical_flags: IDL.Nat32,
+  updated_at: IDL.Nat64,
+});
+
+const ScoreBreakdownIDL = IDL.Record({
+  text_relevance: IDL.Float32,
+  relevance: IDL.Float32,
+  installs: IDL.Float32,
+  stars: IDL.Float32,
+  rating: IDL.Float32,
+  critical_penalty: IDL.Float32,
+  total: IDL.Float32,
+});
+
 const SkillSearchResultIDL = IDL.Record({
-  skill: SkillIDL,
+  skill: SkillSummaryIDL,
   relevance_score: IDL.Float32,
+  score_breakdown: IDL.Opt(ScoreBreakdownIDL),
+});
+
+const SkillPageIDL = IDL.Record({
+  skills: IDL.Vec(SkillSummaryIDL),
+  next_cursor: IDL.Opt(IDL.Text),
+  total: IDL.Nat32,
+});
+
+const SearchPageIDL = IDL.Record({
+  results: IDL.Vec(SkillSearchResultIDL),
+  next_cursor: IDL.Opt(IDL.Text),
+  total: IDL.Nat32,
 });
 
 const UserProfileIDL = IDL.Record({
@@ -180,13 +222,6 @@ const UserProfileIDL = IDL.Record({
   last_active: IDL.Nat64,
 });
 
-const AnalysisResultIDL = IDL.Record({
-  success: IDL.Bool,
-  skill_id: IDL.Text,
-  analysis: IDL.Opt(SkillAnalysisIDL),
-  error: IDL.Opt(IDL.Text),
-});
-
 const AnalysisPromptIDL = IDL.Record({
   id: IDL.Text,
   name: IDL.Text,
@@ -218,7 +253,12 @@ const ResultText = IDL.Variant({ Ok: IDL.Null, Err: IDL.Text });
 const ResultTextText = IDL.Variant({ Ok: IDL.Text, Err: IDL.Text });
 const ResultNat32Text = IDL.Variant({ Ok: IDL.Nat32, Err: IDL.Text });
 const ResultNat64Text = IDL.Variant({ Ok: IDL.Nat64, Err: IDL.Text });
-const ResultAnalysis = IDL.Variant({ Ok: AnalysisResultIDL, Err: IDL.Text });
+const ResultSkillPage = IDL.Variant({ Ok: SkillPageIDL, Err: IDL.Text });
+const ResultSearchPage = IDL.Variant({ Ok: SearchPageIDL, Err: IDL.Text });
+const ResultExportChunk = IDL.Variant({
+  Ok: IDL.Record({ skills: IDL.Vec(SkillIDL), next_cursor: IDL.Opt(IDL.Text) }),
+  Err: IDL.Text,
+});
 
 const JobStatusIDL = IDL.Variant({
   Pending: IDL.Null,
@@ -270,17 +310,17 @@ const idlFactory = ({ IDL: _IDL }: any) => {
     add_skill: IDL.Func([SkillIDL], [ResultTextText], []),
     add_skills_batch: IDL.Func([IDL.Vec(SkillIDL)], [ResultNat32Text], []),
     get_skill: IDL.Func([IDL.Text], [IDL.Opt(SkillIDL)], ['query']),
-    list_skills: IDL.Func([], [IDL.Vec(SkillIDL)], ['query']),
-    list_skills_page: IDL.Func([IDL.Nat32, IDL.Nat32], [IDL.Vec(SkillIDL), IDL.Nat32], ['query']),
-    list_skills_filtered: IDL.Func([IDL.Nat32, IDL.Nat32, IDL.Text, IDL.Text, IDL.Text], [IDL.Vec(SkillIDL), IDL.Nat32], ['query']),
-    search_skills: IDL.Func([IDL.Text], [IDL.Vec(SkillSearchResultIDL)], ['query']),
-    get_skills_by_category: IDL.Func([IDL.Text], [IDL.Vec(SkillIDL)], ['query']),
-    get_skills_by_owner: IDL.Func([IDL.Text], [IDL.Vec(SkillIDL)], ['query']),
-    get_skills_with_dependencies: IDL.Func([], [IDL.Vec(SkillIDL)], ['query']),
-    get_skills_providing_mcp: IDL.Func([], [IDL.Vec(SkillIDL)], ['query']),
-    get_top_rated_skills: IDL.Func([IDL.Nat32], [IDL.Vec(SkillIDL)], ['query']),
+    export_skills: IDL.Func([IDL.Opt(IDL.Text)], [ResultExportChunk], ['query']),
+    list_skills_page: IDL.Func([IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
+    list_skills_filtered: IDL.Func([IDL.Nat32, IDL.Opt(IDL.Text), IDL.Text, IDL.Text, IDL.Text], [ResultSkillPage], ['query']),
+    search_skills: IDL.Func([IDL.Text, IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSearchPage], ['query']),
+    get_skills_by_category: IDL.Func([IDL.Text, IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
+    get_skills_by_owner: IDL.Func([IDL.Text, IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
+    get_skills_with_dependencies: IDL.Func([IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
+    get_skills_providing_mcp: IDL.Func([IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
+    get_top_rated_skills: IDL.Func([IDL.Nat32], [IDL.Vec(SkillSummaryIDL)], ['query']),
     get_categories: IDL.Func([], [IDL.Vec(IDL.Text)], ['query']),
-    get_unanalyzed_skills: IDL.Func([], [IDL.Vec(SkillIDL)], ['query']),
+    get_unanalyzed_skills: IDL.Func([IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
     get_install_command: IDL.Func([IDL.Text], [IDL.Opt(IDL.Text)], ['query']),
     record_install: IDL.Func([IDL.Text], [ResultNat64Text], []),
 
@@ -295,11 +335,8 @@ const idlFactory = ({ IDL: _IDL }: any) => {
     set_skill_files: IDL.Func([IDL.Text, IDL.Vec(SkillFile)], [ResultTextText], []),
     add_skill_file: IDL.Func([IDL.Text, SkillFile], [ResultTextText], []),
 
-    // Analysis (legacy HTTP outcall path - deprecated, use job queue instead)
-    analyze_skill: IDL.Func([IDL.Text, AnalysisModel], [ResultAnalysis], []),
-
     // Analysis Job Queue
-    request_analysis: IDL.Func([IDL.Text, AnalysisModel], [ResultTextText], []),
+    request_analysis: IDL.Func([IDL.Text, IDL.Text, IDL.Opt(IDL.Text)], [ResultTextText], []),
     get_job_status: IDL.Func([IDL.Text], [IDL.Opt(IDL.Tuple(JobStatusIDL, IDL.Opt(IDL.Text)))], ['query']),
     get_analyzed_models: IDL.Func([IDL.Text], [IDL.Vec(IDL.Text)], ['query']),
     get_pending_job_count: IDL.Func([], [IDL.Nat64], ['query']),
@@ -350,9 +387,9 @@ const idlFactory = ({ IDL: _IDL }: any) => {
     // Stats
     get_stats: IDL.Func([], [IDL.Nat64, IDL.Nat64, IDL.Nat64, IDL.Nat64], ['query']),
     get_analysis_stats: IDL.Func([], [IDL.Nat64, IDL.Nat64, IDL.Nat64, IDL.Nat64], ['query']),
-    get_skills_with_flags: IDL.Func([], [IDL.Vec(IDL.Tuple(SkillIDL, IDL.Vec(RatingFlag)))], ['query']),
+    get_skills_with_flags: IDL.Func([IDL.Nat32, IDL.Opt(IDL.Text)], [ResultSkillPage], ['query']),
     get_skill_topic_rating: IDL.Func([IDL.Text, RatingTopic], [IDL.Opt(TopicRating)], ['query']),
-    get_skills_by_topic_rating: IDL.Func([RatingTopic, IDL.Nat32], [IDL.Vec(SkillIDL)], ['query']),
+    get_skills_by_topic_rating: IDL.Func([RatingTopic, IDL.Nat32], [IDL.Vec(SkillSummaryIDL)], ['query']),
   });
 };
 
@@ -525,48 +562,80 @@ function convertSkill(raw: any): Skill {
   };
 }
 
+function convertSummary(raw: any): SkillSummary {
+  const verdict = unwrapOpt(raw.verdict);
+  return {
+    id: raw.id,
+    name: raw.name,
+    owner: raw.owner,
+    repo: raw.repo,
+    stars: raw.stars,
+    install_count: raw.install_count,
+    overall_rating: unwrapOpt(raw.overall_rating),
+    verdict: verdict ? (unwrapVariantKey(verdict) as SafetyVerdict) : null,
+    is_stale: raw.is_stale,
+    category: unwrapOpt(raw.category),
+    info_flags: raw.info_flags,
+    warning_flags: raw.warning_flags,
+    critical_flags: raw.critical_flags,
+    updated_at: raw.updated_at,
+  };
+}
+
+function convertPage(result: any): SkillPage {
+  if ('Err' in result) {
+    throw new Error(result.Err);
+  }
+  return {
+    skills: result.Ok.skills.map(convertSummary),
+    nextCursor: unwrapOpt(result.Ok.next_cursor),
+    total: result.Ok.total,
+  };
+}
+
 // ============================================================================
 // Public API
 // ============================================================================
 
-/** Fetch all skills from the canister */
-export async function listSkills(): Promise<Skill[]> {
+/** Fetch every skill (full records) by following the export stream chunk by chunk */
+export async function exportSkills(): Promise<Skill[]> {
   const actor = await getActor();
-  const raw = await actor.list_skills();
-  return raw.map(convertSkill);
+  const skills: Skill[] = [];
+  let cursor: string | null = null;
+  do {
+    const result = await actor.export_skills(cursor ? [cursor] : []);
+    if ('Err' in result) {
+      throw new Error(result.Err);
+    }
+    skills.push(...result.Ok.skills.map(convertSkill));
+    cursor = unwrapOpt(result.Ok.next_cursor);
+  } while (cursor);
+  return skills;
 }
 
-/** Fetch a page of skills (sorted by stars desc on canister). Returns [skills, totalCount]. */
-export async function listSkillsPage(limit: number, offset: number): Promise<{ skills: Skill[]; total: number }> {
+/** Fetch a page of skills (sorted by stars desc on canister). Pass `nextCursor` back for the next page. */
+export async function listSkillsPage(limit: number, cursor: string | null): Promise<SkillPage> {
   const actor = await getActor();
-  const [raw, total] = await actor.list_skills_page(limit, offset);
-  return {
-    skills: raw.map(convertSkill),
-    total: Number(total),
-  };
+  return convertPage(await actor.list_skills_page(limit, cursor ? [cursor] : []));
 }
 
-/** Server-side paginated + filtered + sorted skill listing. */
+/** Server-side paginated + filtered + sorted skill listing. Cursors are only valid for the same sort/search/category. */
 export async function listSkillsFiltered(
   limit: number,
-  offset: number,
+  cursor: string | null,
   sortBy: string,
   search: string,
   category: string,
-): Promise<{ skills: Skill[]; total: number }> {
+): Promise<SkillPage> {
   // Check cache first
-  const cacheKey = `${limit}:${offset}:${sortBy}:${search}:${category}`;
+  const cacheKey = `${limit}:${cursor ?? ''}:${sortBy}:${search}:${category}`;
   const cached = skillCache.getSkillsList(cacheKey);
   if (cached) {
     return cached;
   }
   
   const actor = await getActor();
-  const [raw, total] = await actor.list_skills_filtered(limit, offset, sortBy, search, category);
-  const result = {
-    skills: raw.map(convertSkill),
-    total: Number(total),
-  };
+  const result = convertPage(await actor.list_skills_filtered(limit, cursor ? [cursor] : [], sortBy, search, category));
   
   // Cache the result
   skillCache.setSkillsList(cacheKey, result);
@@ -584,23 +653,32 @@ export async function recordInstall(skillId: string): Promise<number> {
   return Number(result.Ok);
 }
 
-/** Search skills by query string */
-export async function searchSkills(query: string): Promise<SkillSearchResult[]> {
+/** Search skills by query string, best matches first */
+export async function searchSkills(query: string, limit = 20, cursor: string | null = null): Promise<SearchPage> {
   // Check cache first
-  const cached = skillCache.getSearch(query);
+  const cacheKey = `${query}:${limit}:${cursor ?? ''}`;
+  const cached = skillCache.getSearch(cacheKey);
   if (cached) {
     return cached;
   }
   
   const actor = await getActor();
-  const raw = await actor.search_skills(query);
-  const result = raw.map((r: any) => ({
-    skill: convertSkill(r.skill),
-    relevance_score: r.relevance_score,
-  }));
+  const raw = await actor.search_skills(query, limit, cursor ? [cursor] : []);
+  if ('Err' in raw) {
+    throw new Error(raw.Err);
+  }
+  const result: SearchPage = {
+    results: raw.Ok.results.map((r: any): SkillSearchResult => ({
+      skill: convertSummary(r.skill),
+      relevance_score: r.relevance_score,
+      score_breakdown: unwrapOpt(r.score_breakdown),
+    })),
+    nextCursor: unwrapOpt(raw.Ok.next_cursor),
+    total: raw.Ok.total,
+  };
   
   // Cache the result
-  skillCache.setSearch(query, result);
+  skillCache.setSearch(cacheKey, result);
   
   return result;
 }
@@ -626,25 +704,23 @@ export async function getSkill(id: string): Promise<Skill | null> {
   return skill;
 }
 
-/** Get skills by category */
-export async function getSkillsByCategory(category: string): Promise<Skill[]> {
+/** Get a page of skills in a category */
+export async function getSkillsByCategory(category: string, limit: number, cursor: string | null = null): Promise<SkillPage> {
   const actor = await getActor();
-  const raw = await actor.get_skills_by_category(category);
-  return raw.map(convertSkill);
+  return convertPage(await actor.get_skills_by_category(category, limit, cursor ? [cursor] : []));
 }
 
-/** Get skills by owner */
-export async function getSkillsByOwner(owner: string): Promise<Skill[]> {
+/** Get a page of an owner's skills */
+export async function getSkillsByOwner(owner: string, limit: number, cursor: string | null = null): Promise<SkillPage> {
   const actor = await getActor();
-  const raw = await actor.get_skills_by_owner(owner);
-  return raw.map(convertSkill);
+  return convertPage(await actor.get_skills_by_owner(owner, limit, cursor ? [cursor] : []));
 }
 
 /** Get top rated skills */
-export async function getTopRatedSkills(limit: number): Promise<Skill[]> {
+export async function getTopRatedSkills(limit: number): Promise<SkillSummary[]> {
   const actor = await getActor();
   const raw = await actor.get_top_rated_skills(limit);
-  return raw.map(convertSkill);
+  return raw.map(convertSummary);
 }
 
 /** Get all categories */
@@ -742,23 +818,11 @@ export async function removeAnthropicKey(agent: HttpAgent): Promise<void> {
   }
 }
 
-/** Analyze a skill using the user's API key (legacy direct Anthropic call) */
-export async function analyzeSkill(agent: HttpAgent, skillId: string, model: string): Promise<AnalysisResult> {
-  const actor = getAuthenticatedActor(agent);
-  const modelVariant: Record<string, null> = {};
-  modelVariant[model] = null;
-  const result = await actor.analyze_skill(skillId, modelVariant);
-  if ('Err' in result) {
-    throw new Error(result.Err);
-  }
-  const raw = result.Ok;
-  return {
-    success: raw.success,
-    skill_id: raw.skill_id,
-    analysis: raw.analysis.length > 0 ? convertAnalysis(raw.analysis[0]) : null,
-    error: raw.error.length > 0 ? raw.error[0] : null,
-  };
-}
+/** Canister model registry ids for the model names the UI offers */
+const MODEL_IDS: Record<string, string> = {
+  Haiku: 'claude-haiku-4-5',
+  Opus: 'claude-opus-4-5',
+};
 
 // ============================================================================
 // TEE Integration — Phala Cloud
@@ -794,9 +858,7 @@ export async function getTeeWorkerUrl(): Promise<string | null> {
 /** Submit an analysis request to the job queue. Returns a job_id for polling. */
 export async function requestAnalysis(agent: HttpAgent, skillId: string, model: string): Promise<string> {
   const actor = getAuthenticatedActor(agent);
-  const modelVariant: Record<string, null> = {};
-  modelVariant[model] = null;
-  const result = await actor.request_analysis(skillId, modelVariant);
+  const result = await actor.request_analysis(skillId, MODEL_IDS[model] ?? model, []);
   if ('Err' in result) {
     throw new Error(result.Err);
   }
@@ -1,8 +1,8 @@
 import { writable, derived } from 'svelte/store';
-import type { Skill, SortOption, Stats, AnalysisModel, ViewMode } from './types';
+import type { SkillSummary, SortOption, Stats, AnalysisModel, ViewMode } from './types';
 
 // State stores
-export const skills = writable<Skill[]>([]);
+export const skills = writable<SkillSummary[]>([]);
 export const searchQuery = writable('');
 export const selectedCategory = writable<string | null>(null);
 export const sortBy = writable<SortOption>('rating');
@@ -30,39 +30,24 @@ export const showOnlyAnalyzed = writable(false);
 export const categories = derived(skills, ($skills) => {
   const cats = new Set<string>();
   $skills.forEach((skill) => {
-    if (skill.analysis) {
-      cats.add(skill.analysis.primary_category);
-      skill.analysis.secondary_categories.forEach((cat) => cats.add(cat));
+    if (skill.category) {
+      cats.add(skill.category);
     }
   });
   return Array.from(cats).sort();
 });
 
-export const tags = derived(skills, ($skills) => {
-  const allTags = new Set<string>();
-  $skills.forEach((skill) => {
-    if (skill.analysis) {
-      skill.analysis.tags.forEach((tag) => allTags.add(tag));
-    }
-  });
-  return Array.from(allTags).sort();
-});
-
 export const stats = derived(skills, ($skills): Stats => ({
   total_skills: $skills.length,
-  analyzed_skills: $skills.filter((s) => s.analysis !== null).length,
+  analyzed_skills: $skills.filter((s) => s.overall_rating !== null).length,
   total_installs: $skills.reduce((sum, s) => sum + Number(s.install_count), 0),
   total_users: 0,
 }));
 
-export const skillsWithMcp = derived(skills, ($skills) =>
-  $skills.filter((s) => s.analysis?.provides_mcp === true)
-);
-
 export const avgRating = derived(skills, ($skills) => {
-  const analyzed = $skills.filter((s) => s.analysis !== null);
+  const analyzed = $skills.filter((s) => s.overall_rating !== null);
   if (analyzed.length === 0) return 0;
   return (
-    analyzed.reduce((sum, s) => sum + (s.analysis?.ratings.overall ?? 0), 0) / analyzed.length
+    analyzed.reduce((sum, s) => sum + (s.overall_rating ?? 0), 0) / analyzed.length
   );
 });
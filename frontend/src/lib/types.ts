@@ -167,6 +167,39 @@ export interface Skill {
   source: string;
 }
 
+export type SafetyVerdict = 'Safe' | 'Ok' | 'Caution' | 'Warning' | 'Danger';
+
+/** Lightweight projection of a skill returned by listings (fetch the full record with getSkill) */
+export interface SkillSummary {
+  id: string;
+  name: string;
+  owner: string;
+  repo: string;
+  stars: number;
+  install_count: bigint;
+  overall_rating: number | null;
+  verdict: SafetyVerdict | null;
+  is_stale: boolean;
+  category: string | null;
+  info_flags: number;
+  warning_flags: number;
+  critical_flags: number;
+  updated_at: bigint;
+}
+
+/** One page of a cursor-paginated listing; pass `nextCursor` back for the next page */
+export interface SkillPage {
+  skills: SkillSummary[];
+  nextCursor: string | null;
+  total: number;
+}
+
+export interface SearchPage {
+  results: SkillSearchResult[];
+  nextCursor: string | null;
+  total: number;
+}
+
 // Verification types
 export interface FileVerifyResult {
   path: string;
@@ -185,9 +218,21 @@ export interface SkillVerifyResult {
   extra_files: string[];
 }
 
+/** Per-signal contributions to a search result's ranking score */
+export interface ScoreBreakdown {
+  text_relevance: number;
+  relevance: number;
+  installs: number;
+  stars: number;
+  rating: number;
+  critical_penalty: number;
+  total: number;
+}
+
 export interface SkillSearchResult {
-  skill: Skill;
+  skill: SkillSummary;
   relevance_score: number;
+  score_breakdown: ScoreBreakdown | null;
 }
 
 export interface UserProfile {
@@ -1,6 +1,6 @@
 import { HttpAgent, Actor } from '@dfinity/agent';
 import { IDL } from '@dfinity/candid';
-import type { Skill, SkillSearchResult, Stats, SkillsicConfig } from './types.js';
+import type { Skill, SkillSummary, SafetyVerdict, SkillSearchResult, Page, Stats, SkillsicConfig } from './types.js';
 
 const DEFAULT_CONFIG: SkillsicConfig = {
   canisterHost: 'https://icp0.io',
@@ -75,14 +75,31 @@ function createIdlFactory() {
     install_count: IDL.Nat64, created_at: IDL.Nat64, updated_at: IDL.Nat64, source: IDL.Text,
   });
 
-  const SkillSearchResultIDL = IDL.Record({ skill: SkillIDL, relevance_score: IDL.Float32 });
+  const SkillSummaryIDL = IDL.Record({
+    id: IDL.Text, name: IDL.Text, owner: IDL.Text, repo: IDL.Text,
+    stars: IDL.Nat32, install_count: IDL.Nat64, overall_rating: IDL.Opt(IDL.Float32),
+    verdict: IDL.Opt(IDL.Variant({ Safe: IDL.Null, Ok: IDL.Null, Caution: IDL.Null, Warning: IDL.Null, Danger: IDL.Null })),
+    is_stale: IDL.Bool, category: IDL.Opt(IDL.Text),
+    info_flags: IDL.Nat32, warning_flags: IDL.Nat32, critical_flags: IDL.Nat32, updated_at: IDL.Nat64,
+  });
+
+  const ScoreBreakdownIDL = IDL.Record({
+    text_relevance: IDL.Float32, relevance: IDL.Float32, installs: IDL.Float32, stars: IDL.Float32,
+    rating: IDL.Float32, critical_penalty: IDL.Float32, total: IDL.Float32,
+  });
+
+  const SkillSearchResultIDL = IDL.Record({
+    skill: SkillSummaryIDL, relevance_score: IDL.Float32, score_breakdown: IDL.Opt(ScoreBreakdownIDL),
+  });
+
+  const SkillPageIDL = IDL.Record({ skills: IDL.Vec(SkillSummaryIDL), next_cursor: IDL.Opt(IDL.Text), total: IDL.Nat32 });
+  const SearchPageIDL = IDL.Record({ results: IDL.Vec(SkillSearchResultIDL), next_cursor: IDL.Opt(IDL.Text), total: IDL.Nat32 });
 
   return ({ IDL: _IDL }: any) => IDL.Service({
-    list_skills: IDL.Func([], [IDL.Vec(SkillIDL)], ['query']),
     get_skill: IDL.Func([IDL.Text], [IDL.Opt(SkillIDL)], ['query']),
-    search_skills: IDL.Func([IDL.Text], [IDL.Vec(SkillSearchResultIDL)], ['query']),
-    get_skills_by_category: IDL.Func([IDL.Text], [IDL.Vec(SkillIDL)], ['query']),
-    get_top_rated_skills: IDL.Func([IDL.Nat32], [IDL.Vec(SkillIDL)], ['query']),
+    search_skills: IDL.Func([IDL.Text, IDL.Nat32, IDL.Opt(IDL.Text)], [IDL.Variant({ Ok: SearchPageIDL, Err: IDL.Text })], ['query']),
+    get_skills_by_category: IDL.Func([IDL.Text, IDL.Nat32, IDL.Opt(IDL.Text)], [IDL.Variant({ Ok: SkillPageIDL, Err: IDL.Text })], ['query']),
+    get_top_rated_skills: IDL.Func([IDL.Nat32], [IDL.Vec(SkillSummaryIDL)], ['query']),
     get_categories: IDL.Func([], [IDL.Vec(IDL.Text)], ['query']),
     get_stats: IDL.Func([], [IDL.Tuple(IDL.Nat64, IDL.Nat64, IDL.Nat64, IDL.Nat64)], ['query']),
     get_install_command: IDL.Func([IDL.Text], [IDL.Opt(IDL.Text)], ['query']),
@@ -162,6 +179,26 @@ function convertSkill(raw: any): Skill {
   };
 }
 
+function convertSummary(raw: any): SkillSummary {
+  const verdict = unwrapOpt(raw.verdict) as Record<string, any> | null;
+  return {
+    id: raw.id,
+    name: raw.name,
+    owner: raw.owner,
+    repo: raw.repo,
+    stars: raw.stars,
+    install_count: Number(raw.install_count),
+    overall_rating: unwrapOpt(raw.overall_rating),
+    verdict: verdict ? (variantKey(verdict) as SafetyVerdict) : null,
+    is_stale: raw.is_stale,
+    category: unwrapOpt(raw.category),
+    info_flags: raw.info_flags,
+    warning_flags: raw.warning_flags,
+    critical_flags: raw.critical_flags,
+    updated_at: Number(raw.updated_at),
+  };
+}
+
 // ============================================================================
 // Client
 // ============================================================================
@@ -185,13 +222,19 @@ export class SkillsicClient {
     return this.actor;
   }
 
-  async searchSkills(query: string): Promise<SkillSearchResult[]> {
+  async searchSkills(query: string, limit: number = 20, cursor: string | null = null): Promise<Page<SkillSearchResult>> {
     const actor = await this.getActor();
-    const raw = await actor.search_skills(query);
-    return raw.map((r: any) => ({
-      skill: convertSkill(r.skill),
-      relevance_score: r.relevance_score,
-    }));
+    const result = await actor.search_skills(query, limit, cursor ? [cursor] : []);
+    if ('Err' in result) throw new Error(result.Err);
+    return {
+      items: result.Ok.results.map((r: any) => ({
+        skill: convertSummary(r.skill),
+        relevance_score: r.relevance_score,
+        score_breakdown: unwrapOpt(r.score_breakdown),
+      })),
+      next_cursor: unwrapOpt(result.Ok.next_cursor),
+      total: result.Ok.total,
+    };
   }
 
   async getSkill(id: string): Promise<Skill | null> {
@@ -201,16 +244,21 @@ export class SkillsicClient {
     return result ? convertSkill(result) : null;
   }
 
-  async getTopRatedSkills(limit: number = 10): Promise<Skill[]> {
+  async getTopRatedSkills(limit: number = 10): Promise<SkillSummary[]> {
     const actor = await this.getActor();
     const raw = await actor.get_top_rated_skills(limit);
-    return raw.map(convertSkill);
+    return raw.map(convertSummary);
   }
 
-  async getSkillsByCategory(category: string): Promise<Skill[]> {
+  async getSkillsByCategory(category: string, limit: number = 50, cursor: string | null = null): Promise<Page<SkillSummary>> {
     const actor = await this.getActor();
-    const raw = await actor.get_skills_by_category(category);
-    return raw.map(convertSkill);
+    const result = await actor.get_skills_by_category(category, limit, cursor ? [cursor] : []);
+    if ('Err' in result) throw new Error(result.Err);
+    return {
+      items: result.Ok.skills.map(convertSummary),
+      next_cursor: unwrapOpt(result.Ok.next_cursor),
+      total: result.Ok.total,
+    };
   }
 
   async getCategories(): Promise<string[]> {
@@ -229,7 +277,7 @@ export class SkillsicClient {
     };
   }
 
-  getInstallCommand(skill: Skill): string {
+  getInstallCommand(skill: Pick<Skill, 'owner' | 'repo' | 'name'>): string {
     if (skill.repo === skill.name) {
       return `npx skills add ${skill.owner}/${skill.repo}`;
     }
@@ -1,3 +1,3 @@
 export { SkillsicClient } from './client.js';
 export { createServer, runServer } from './server.js';
-export type { Skill, SkillAnalysis, SkillSearchResult, SkillsicConfig } from './types.js';
+export type { Skill, SkillAnalysis, SkillSummary, SkillSearchResult, Page, SkillsicConfig } from './types.js';
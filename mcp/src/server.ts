@@ -35,6 +35,10 @@ export function createServer(): Server {
                 type: 'string',
                 description: 'Search query (e.g., "rust", "web development", "deployment")',
               },
+              cursor: {
+                type: 'string',
+                description: 'next_cursor from a previous call, to fetch the next page',
+              },
             },
             required: ['query'],
           },
@@ -76,6 +80,10 @@ export function createServer(): Server {
                 type: 'string',
                 description: 'Category name (e.g., "web", "blockchain", "programming")',
               },
+              cursor: {
+                type: 'string',
+                description: 'next_cursor from a previous call, to fetch the next page',
+              },
             },
             required: ['category'],
           },
@@ -112,23 +120,26 @@ export function createServer(): Server {
 
     switch (name) {
       case 'search_skills': {
-        const query = (args as { query: string }).query;
-        const results = await client.searchSkills(query);
+        const { query, cursor } = args as { query: string; cursor?: string };
+        const page = await client.searchSkills(query, 20, cursor ?? null);
         return {
           content: [
             {
               type: 'text',
               text: JSON.stringify(
-                results.map((r) => ({
-                  id: r.skill.id,
-                  name: r.skill.name,
-                  description: r.skill.description,
-                  rating: r.skill.analysis?.ratings.overall ?? 'unrated',
-                  category: r.skill.analysis?.primary_category ?? 'unknown',
-                  tags: r.skill.analysis?.tags ?? [],
-                  install: client.getInstallCommand(r.skill),
-                  relevance: r.relevance_score,
-                })),
+                {
+                  total: page.total,
+                  next_cursor: page.next_cursor,
+                  results: page.items.map((r) => ({
+                    id: r.skill.id,
+                    name: r.skill.name,
+                    rating: r.skill.overall_rating ?? 'unrated',
+                    category: r.skill.category ?? 'unknown',
+                    verdict: r.skill.verdict,
+                    install: client.getInstallCommand(r.skill),
+                    relevance: r.relevance_score,
+                  })),
+                },
                 null,
                 2
               ),
@@ -191,8 +202,9 @@ export function createServer(): Server {
                 skills.map((s) => ({
                   id: s.id,
                   name: s.name,
-                  rating: s.analysis?.ratings.overall ?? 'unrated',
-                  description: s.description,
+                  rating: s.overall_rating ?? 'unrated',
+                  category: s.category,
+                  verdict: s.verdict,
                   install: client.getInstallCommand(s),
                 })),
                 null,
@@ -204,20 +216,25 @@ export function createServer(): Server {
       }
 
       case 'get_skills_by_category': {
-        const category = (args as { category: string }).category;
-        const skills = await client.getSkillsByCategory(category);
+        const { category, cursor } = args as { category: string; cursor?: string };
+        const page = await client.getSkillsByCategory(category, 50, cursor ?? null);
         return {
           content: [
             {
               type: 'text',
               text: JSON.stringify(
-                skills.map((s) => ({
-                  id: s.id,
-                  name: s.name,
-                  rating: s.analysis?.ratings.overall ?? 'unrated',
-                  description: s.description,
-                  install: client.getInstallCommand(s),
-                })),
+                {
+                  total: page.total,
+                  next_cursor: page.next_cursor,
+                  skills: page.items.map((s) => ({
+                    id: s.id,
+                    name: s.name,
+                    rating: s.overall_rating ?? 'unrated',
+                    category: s.category,
+                    verdict: s.verdict,
+                    install: client.getInstallCommand(s),
+                  })),
+                },
                 null,
                 2
               ),
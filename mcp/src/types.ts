@@ -92,9 +92,48 @@ export interface Skill {
   source: string;
 }
 
+export type SafetyVerdict = 'Safe' | 'Ok' | 'Caution' | 'Warning' | 'Danger';
+
+// Listing projection of a skill (fetch the full record with getSkill)
+export interface SkillSummary {
+  id: string;
+  name: string;
+  owner: string;
+  repo: string;
+  stars: number;
+  install_count: number;
+  overall_rating: number | null;
+  verdict: SafetyVerdict | null;
+  is_stale: boolean;
+  category: string | null;
+  info_flags: number;
+  warning_flags: number;
+  critical_flags: number;
+  updated_at: number;
+}
+
+// Per-signal contributions to a search result's ranking score
+export interface ScoreBreakdown {
+  text_relevance: number;
+  relevance: number;
+  installs: number;
+  stars: number;
+  rating: number;
+  critical_penalty: number;
+  total: number;
+}
+
 export interface SkillSearchResult {
-  skill: Skill;
+  skill: SkillSummary;
   relevance_score: number;
+  score_breakdown: ScoreBreakdown | null;
+}
+
+// One page of a cursor-paginated listing; pass next_cursor back for the next page
+export interface Page<T> {
+  items: T[];
+  next_cursor: string | null;
+  total: number;
 }
 
 export interface Stats {
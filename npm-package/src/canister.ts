@@ -1,6 +1,6 @@
 import { Actor, HttpAgent } from '@dfinity/agent';
 import { IDL } from '@dfinity/candid';
-import type { Skill, SkillSearchResult } from './types.js';
+import type { Skill, SkillSummary, SkillSearchResult } from './types.js';
 
 // Canister ID on IC mainnet
 const CANISTER_ID = 'fs4ea-5qaaa-aaaak-qvwfq-cai';
@@ -164,19 +164,69 @@ const SkillIDL = IDL.Record({
   'source': IDL.Text,
 });
 
+const SkillSummaryIDL = IDL.Record({
+  'id': IDL.Text,
+  'name': IDL.Text,
+  'owner': IDL.Text,
+  'repo': IDL.Text,
+  'stars': IDL.Nat32,
+  'install_count': IDL.Nat64,
+  'overall_rating': IDL.Opt(IDL.Float32),
+  'verdict': IDL.Opt(IDL.Variant({
+    'Safe': IDL.Null,
+    'Ok': IDL.Null,
+    'Caution': IDL.Null,
+    'Warning': IDL.Null,
+    'Danger': IDL.Null,
+  })),
+  'is_stale': IDL.Bool,
+  'category': IDL.Opt(IDL.Text),
+  'info_flags': IDL.Nat32,
+  'warning_flags': IDL.Nat32,
+  'critical_flags': IDL.Nat32,
+  'updated_at': IDL.Nat64,
+});
+
+const ScoreBreakdownIDL = IDL.Record({
+  'text_relevance': IDL.Float32,
+  'relevance': IDL.Float32,
+  'installs': IDL.Float32,
+  'stars': IDL.Float32,
+  'rating': IDL.Float32,
+  'critical_penalty': IDL.Float32,
+  'total': IDL.Float32,
+});
+
 const SkillSearchResultIDL = IDL.Record({
-  'skill': SkillIDL,
+  'skill': SkillSummaryIDL,
   'relevance_score': IDL.Float32,
+  'score_breakdown': IDL.Opt(ScoreBreakdownIDL),
+});
+
+const SkillPageIDL = IDL.Record({
+  'skills': IDL.Vec(SkillSummaryIDL),
+  'next_cursor': IDL.Opt(IDL.Text),
+  'total': IDL.Nat32,
+});
+
+const SearchPageIDL = IDL.Record({
+  'results': IDL.Vec(SkillSearchResultIDL),
+  'next_cursor': IDL.Opt(IDL.Text),
+  'total': IDL.Nat32,
 });
 
 // IDL factory for our canister interface
 const idlFactory = () => {
   return IDL.Service({
     'get_skill': IDL.Func([IDL.Text], [IDL.Opt(SkillIDL)], ['query']),
-    'search_skills': IDL.Func([IDL.Text], [IDL.Vec(SkillSearchResultIDL)], ['query']),
+    'search_skills': IDL.Func(
+      [IDL.Text, IDL.Nat32, IDL.Opt(IDL.Text)],
+      [IDL.Variant({ 'Ok': SearchPageIDL, 'Err': IDL.Text })],
+      ['query']
+    ),
     'list_skills_filtered': IDL.Func(
-      [IDL.Nat32, IDL.Nat32, IDL.Text, IDL.Text, IDL.Text],
-      [IDL.Vec(SkillIDL), IDL.Nat32],
+      [IDL.Nat32, IDL.Opt(IDL.Text), IDL.Text, IDL.Text, IDL.Text],
+      [IDL.Variant({ 'Ok': SkillPageIDL, 'Err': IDL.Text })],
       ['query']
     ),
     'get_stats': IDL.Func([], [IDL.Nat64, IDL.Nat64, IDL.Nat64, IDL.Nat64], ['query']),
@@ -218,40 +268,48 @@ export async function getSkill(skillId: string): Promise<Skill | null> {
 }
 
 /**
- * Search for skills by query
+ * Search for skills by query (best matches first, at most `limit`)
  */
-export async function searchSkills(query: string): Promise<SkillSearchResult[]> {
+export async function searchSkills(query: string, limit = 20): Promise<SkillSearchResult[]> {
   const actor = await getActor();
-  const results = await actor.search_skills(query);
+  const result = await actor.search_skills(query, limit, []);
+  if ('Err' in result) {
+    throw new Error(result.Err);
+  }
   
-  return results.map((r: any) => ({
-    skill: transformSkill(r.skill),
+  return result.Ok.results.map((r: any) => ({
+    skill: transformSummary(r.skill),
     relevance_score: r.relevance_score,
+    score_breakdown: r.score_breakdown[0] ?? null,
   }));
 }
 
 /**
- * List skills with filters
+ * List skills with filters. Pass `nextCursor` back to get the next page.
  */
 export async function listSkillsFiltered(
   limit: number,
-  offset: number,
+  cursor: string | null,
   sortBy: string,
   search: string,
   category: string
-): Promise<{ skills: Skill[]; total: number }> {
+): Promise<{ skills: SkillSummary[]; nextCursor: string | null; total: number }> {
   const actor = await getActor();
-  const [skills, total] = await actor.list_skills_filtered(
+  const result = await actor.list_skills_filtered(
     limit,
-    offset,
+    cursor ? [cursor] : [],
     sortBy,
     search,
     category
   );
+  if ('Err' in result) {
+    throw new Error(result.Err);
+  }
   
   return {
-    skills: skills.map(transformSkill),
-    total: Number(total),
+    skills: result.Ok.skills.map(transformSummary),
+    nextCursor: result.Ok.next_cursor[0] ?? null,
+    total: Number(result.Ok.total),
   };
 }
 
@@ -299,6 +357,25 @@ function transformSkill(raw: any): Skill {
   };
 }
 
+function transformSummary(raw: any): SkillSummary {
+  return {
+    id: raw.id,
+    name: raw.name,
+    owner: raw.owner,
+    repo: raw.repo,
+    stars: Number(raw.stars),
+    install_count: BigInt(raw.install_count),
+    overall_rating: raw.overall_rating[0] ?? null,
+    verdict: raw.verdict[0] ?? null,
+    is_stale: raw.is_stale,
+    category: raw.category[0] ?? null,
+    info_flags: raw.info_flags,
+    warning_flags: raw.warning_flags,
+    critical_flags: raw.critical_flags,
+    updated_at: BigInt(raw.updated_at),
+  };
+}
+
 function transformAnalysis(raw: any): any {
   return {
     ratings: {
@@ -5,8 +5,8 @@ import chalk from 'chalk';
 import ora from 'ora';
 import { spawn } from 'child_process';
 import { getSkill, searchSkills, listSkillsFiltered, getStats } from './canister.js';
-import { checkSafety, filterAnalyzedSkills, filterSafeSkills, sortBySafety } from './safety.js';
-import type { Skill, SafetyLevel, SafetyCheck } from './types.js';
+import { checkSafety, summarySafetyLevel, filterAnalyzedSkills, filterSafeSkills, sortBySafety } from './safety.js';
+import type { Skill, SkillSummary, SafetyLevel, SafetyCheck } from './types.js';
 import { getTopicName, getFlagTypeName, getFlagSeverityName } from './types.js';
 
 const VERSION = '1.0.0';
@@ -110,15 +110,13 @@ function printSafetyReport(safety: SafetyCheck): void {
   }
 }
 
-function printSkillSummary(skill: Skill, showSafety = true): void {
-  const safety = checkSafety(skill);
-  const badge = formatSafetyBadge(safety.level);
-  const rating = formatRating(safety.overallRating);
+function printSkillSummary(skill: SkillSummary): void {
+  const badge = formatSafetyBadge(summarySafetyLevel(skill));
+  const rating = formatRating(skill.overall_rating);
   const installs = Number(skill.install_count).toLocaleString();
   
   console.log(`${badge} ${chalk.bold(skill.owner + '/' + skill.name)}`);
-  console.log(`   ${chalk.gray(skill.description.slice(0, 80))}${skill.description.length > 80 ? '...' : ''}`);
-  console.log(`   ${rating} | ${chalk.gray(`${installs} installs`)} | ${chalk.gray(skill.analysis?.primary_category || 'uncategorized')}`);
+  console.log(`   ${rating} | ${chalk.gray(`${installs} installs`)} | ${chalk.gray(skill.category || 'uncategorized')}`);
   console.log();
 }
 
@@ -217,25 +215,23 @@ program
         const results = await searchSkills(skillId);
         
         // Filter to safe/analyzed skills
-        const safeResults = results
-          .map(r => r.skill)
-          .filter(s => s.analysis !== null)
-          .filter(s => {
-            const safety = checkSafety(s);
-            return safety.level === 'safe' || safety.level === 'caution';
-          });
+        const safeResults = filterSafeSkills(filterAnalyzedSkills(results.map(r => r.skill)));
         
         if (safeResults.length > 0) {
-          skill = safeResults[0]; // Take the best match
+          skill = await getSkill(safeResults[0].id); // Take the best match
           spinner.stop();
-          console.log();
-          console.log(chalk.gray(`Found matching skill: ${skill.owner}/${skill.name}`));
+          if (skill) {
+            console.log();
+            console.log(chalk.gray(`Found matching skill: ${skill.owner}/${skill.name}`));
+          }
         } else if (results.length > 0) {
           // Has results but none are safe/analyzed
-          skill = results[0].skill;
+          skill = await getSkill(results[0].skill.id);
           spinner.stop();
-          console.log();
-          console.log(chalk.yellow(`Found "${skill.owner}/${skill.name}" but it hasn't been analyzed yet.`));
+          if (skill) {
+            console.log();
+            console.log(chalk.yellow(`Found "${skill.owner}/${skill.name}" but it hasn't been analyzed yet.`));
+          }
         }
       }
       
@@ -353,7 +349,8 @@ program
     const spinner = ora('Searching skills...').start();
     
     try {
-      const results = await searchSkills(query);
+      // Fetch extra candidates since unanalyzed/unsafe ones are filtered out below
+      const results = await searchSkills(query, 50);
       spinner.stop();
       
       if (results.length === 0) {
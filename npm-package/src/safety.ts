@@ -1,4 +1,4 @@
-import type { Skill, SafetyCheck, SafetyLevel, RatingFlag } from './types.js';
+import type { Skill, SkillSummary, SafetyCheck, SafetyLevel, RatingFlag } from './types.js';
 import { getTopicName, getFlagTypeName, getFlagSeverityName } from './types.js';
 
 /**
@@ -179,28 +179,39 @@ function generateSummary(
   return { summary, recommendations };
 }
 
+/**
+ * Estimate a listed skill's safety level from its summary (flag counts and
+ * overall rating). Use checkSafety on the full record before installing.
+ */
+export function summarySafetyLevel(skill: SkillSummary): SafetyLevel {
+  if (skill.overall_rating === null) return 'unknown';
+  if (skill.critical_flags > 0) return 'danger';
+  if (skill.warning_flags > 0) return 'warning';
+  if (skill.overall_rating >= 3.5) return 'safe';
+  return 'caution';
+}
+
 /**
  * Filter skills to only include those that have been analyzed
  */
-export function filterAnalyzedSkills(skills: Skill[]): Skill[] {
-  return skills.filter(s => s.analysis !== null);
+export function filterAnalyzedSkills(skills: SkillSummary[]): SkillSummary[] {
+  return skills.filter(s => s.overall_rating !== null);
 }
 
 /**
  * Filter skills to only include safe ones (safe or caution level)
  */
-export function filterSafeSkills(skills: Skill[]): Skill[] {
+export function filterSafeSkills(skills: SkillSummary[]): SkillSummary[] {
   return skills.filter(s => {
-    if (!s.analysis) return false;
-    const safety = checkSafety(s);
-    return safety.level === 'safe' || safety.level === 'caution';
+    const level = summarySafetyLevel(s);
+    return level === 'safe' || level === 'caution';
   });
 }
 
 /**
  * Sort skills by safety rating (safest first)
  */
-export function sortBySafety(skills: Skill[]): Skill[] {
+export function sortBySafety(skills: SkillSummary[]): SkillSummary[] {
   const levelOrder: Record<SafetyLevel, number> = {
     'safe': 0,
     'caution': 1,
@@ -210,15 +221,10 @@ export function sortBySafety(skills: Skill[]): Skill[] {
   };
 
   return [...skills].sort((a, b) => {
-    const safetyA = checkSafety(a);
-    const safetyB = checkSafety(b);
-    
-    const levelDiff = levelOrder[safetyA.level] - levelOrder[safetyB.level];
+    const levelDiff = levelOrder[summarySafetyLevel(a)] - levelOrder[summarySafetyLevel(b)];
     if (levelDiff !== 0) return levelDiff;
     
     // If same level, sort by overall rating
-    const ratingA = safetyA.overallRating ?? 0;
-    const ratingB = safetyB.overallRating ?? 0;
-    return ratingB - ratingA;
+    return (b.overall_rating ?? 0) - (a.overall_rating ?? 0);
   });
 }
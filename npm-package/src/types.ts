@@ -142,9 +142,46 @@ export interface Skill {
   source: string;
 }
 
+export type SafetyVerdict =
+  | { Safe: null }
+  | { Ok: null }
+  | { Caution: null }
+  | { Warning: null }
+  | { Danger: null };
+
+// Listing projection of a skill (fetch the full record with getSkill)
+export interface SkillSummary {
+  id: string;
+  name: string;
+  owner: string;
+  repo: string;
+  stars: number;
+  install_count: bigint;
+  overall_rating: number | null;
+  verdict: SafetyVerdict | null;
+  is_stale: boolean;
+  category: string | null;
+  info_flags: number;
+  warning_flags: number;
+  critical_flags: number;
+  updated_at: bigint;
+}
+
+// Per-signal contributions to a search result's ranking score
+export interface ScoreBreakdown {
+  text_relevance: number;
+  relevance: number;
+  installs: number;
+  stars: number;
+  rating: number;
+  critical_penalty: number;
+  total: number;
+}
+
 export interface SkillSearchResult {
-  skill: Skill;
+  skill: SkillSummary;
   relevance_score: number;
+  score_breakdown: ScoreBreakdown | null;
 }
 
 // CLI-specific types
@@ -5,7 +5,7 @@
  * The key only exists in TEE memory — never logged, never written to disk.
  */
 
-import type { SkillAnalysis } from './types.js';
+import type { RawAnalysis } from './types.js';
 
 interface AnthropicMessage {
   role: 'user' | 'assistant';
@@ -37,13 +37,13 @@ const ANTHROPIC_API_URL = 'https://api.anthropic.com/v1/messages';
 const ANTHROPIC_VERSION = '2023-06-01';
 
 /**
- * Call the Anthropic Messages API and parse the response into SkillAnalysis.
+ * Call the Anthropic Messages API and extract the analysis object from the response.
  */
 export async function callAnthropic(
   apiKey: string,
   model: string,
   prompt: string,
-): Promise<SkillAnalysis> {
+): Promise<RawAnalysis> {
   const requestBody: AnthropicRequest = {
     model,
     max_tokens: 8192,
@@ -76,10 +76,10 @@ export async function callAnthropic(
 }
 
 /**
- * Parse the JSON from Claude's response text.
- * Handles cases where the JSON is wrapped in markdown code blocks or extra text.
+ * Extract the JSON object from a model's response text, without filling in or
+ * coercing anything. Handles JSON wrapped in markdown code blocks or extra text.
  */
-function parseAnalysisJson(text: string): SkillAnalysis {
+export function parseAnalysisJson(text: string): RawAnalysis {
   // Try to extract JSON from the response
   let jsonStr = text;
 
@@ -96,128 +96,16 @@ function parseAnalysisJson(text: string): SkillAnalysis {
     }
   }
 
-  let raw: any;
+  let raw: unknown;
   try {
     raw = JSON.parse(jsonStr);
   } catch (e) {
     throw new Error(`Failed to parse analysis JSON: ${(e as Error).message}\nRaw text: ${text.substring(0, 500)}`);
   }
+  if (typeof raw !== 'object' || raw === null || Array.isArray(raw)) {
+    throw new Error(`Analysis JSON is not an object\nRaw text: ${text.substring(0, 500)}`);
+  }
 
-  // Validate and normalize the analysis
-  return normalizeAnalysis(raw);
-}
-
-/**
- * Normalize and validate the raw parsed JSON into our SkillAnalysis format.
- * Handles missing fields gracefully.
- */
-function normalizeAnalysis(raw: any): SkillAnalysis {
-  // Validate ratings
-  const ratings = raw.ratings || {};
-  const topics = (ratings.topics || []).map((t: any) => ({
-    topic: String(t.topic || 'Unknown'),
-    score: clamp(Number(t.score) || 0, 0, 100),
-    confidence: clamp(Number(t.confidence) || 50, 0, 100),
-    reasoning: String(t.reasoning || ''),
-  }));
-
-  const flags = (ratings.flags || []).map((f: any) => ({
-    flag_type: String(f.flag_type || 'UnverifiedSource'),
-    severity: String(f.severity || 'Info'),
-    message: String(f.message || ''),
-  }));
-
-  // Normalize MCP dependencies
-  const requiredMcps = (raw.required_mcps || []).map((m: any) => ({
-    name: String(m.name || ''),
-    package: String(m.package || ''),
-    required: Boolean(m.required),
-    indexed: Boolean(m.indexed),   // Whether MCP is indexed in skillsic
-    verified: Boolean(m.verified), // Whether MCP is verified
-    ratings: m.ratings ? {
-      overall: clamp(Number(m.ratings.overall) || 0, 0, 5),
-      topics: (m.ratings.topics || []).map((t: any) => ({
-        topic: String(t.topic || ''),
-        score: clamp(Number(t.score) || 0, 0, 100),
-        confidence: clamp(Number(t.confidence) || 50, 0, 100),
-        reasoning: String(t.reasoning || ''),
-      })),
-      flags: (m.ratings.flags || []).map((f: any) => ({
-        flag_type: String(f.flag_type || ''),
-        severity: String(f.severity || 'Info'),
-        message: String(f.message || ''),
-      })),
-    } : undefined,
-  }));
-
-  // Normalize software dependencies
-  const softwareDeps = (raw.software_deps || []).map((d: any) => ({
-    name: String(d.name || ''),
-    install_cmd: d.install_cmd ? String(d.install_cmd) : undefined,
-    url: d.url ? String(d.url) : undefined,
-    required: Boolean(d.required),
-    ratings: d.ratings ? {
-      overall: clamp(Number(d.ratings.overall) || 0, 0, 5),
-      topics: (d.ratings.topics || []).map((t: any) => ({
-        topic: String(t.topic || ''),
-        score: clamp(Number(t.score) || 0, 0, 100),
-        confidence: clamp(Number(t.confidence) || 50, 0, 100),
-        reasoning: String(t.reasoning || ''),
-      })),
-      flags: (d.ratings.flags || []).map((f: any) => ({
-        flag_type: String(f.flag_type || ''),
-        severity: String(f.severity || 'Info'),
-        message: String(f.message || ''),
-      })),
-    } : undefined,
-  }));
-
-  // Normalize referenced files
-  const referencedFiles = (raw.referenced_files || []).map((f: any) => ({
-    path: String(f.path || ''),
-    context: String(f.context || ''),
-    resolved: Boolean(f.resolved),
-  }));
-
-  // Normalize referenced URLs
-  const referencedUrls = (raw.referenced_urls || []).map((u: any) => ({
-    url: String(u.url || ''),
-    context: String(u.context || ''),
-    fetched: Boolean(u.fetched),
-  }));
-
-  return {
-    ratings: {
-      overall: clamp(Number(ratings.overall) || 0, 0, 5),
-      topics,
-      flags,
-    },
-    primary_category: String(raw.primary_category || 'meta'),
-    secondary_categories: ensureStringArray(raw.secondary_categories),
-    tags: ensureStringArray(raw.tags),
-    has_mcp: Boolean(raw.has_mcp),
-    provides_mcp: Boolean(raw.provides_mcp),
-    required_mcps: requiredMcps,
-    software_deps: softwareDeps,
-    has_references: Boolean(raw.has_references),
-    has_assets: Boolean(raw.has_assets),
-    estimated_token_usage: Number(raw.estimated_token_usage) || 0,
-    summary: String(raw.summary || ''),
-    strengths: ensureStringArray(raw.strengths),
-    weaknesses: ensureStringArray(raw.weaknesses),
-    use_cases: ensureStringArray(raw.use_cases),
-    compatibility_notes: String(raw.compatibility_notes || ''),
-    prerequisites: ensureStringArray(raw.prerequisites),
-    referenced_files: referencedFiles,
-    referenced_urls: referencedUrls,
-  };
-}
-
-function clamp(value: number, min: number, max: number): number {
-  return Math.max(min, Math.min(max, value));
-}
-
-function ensureStringArray(val: any): string[] {
-  if (!Array.isArray(val)) return [];
-  return val.map((v) => String(v));
+  // Submitted as is: the canister validates it against the analysis schema
+  return raw as RawAnalysis;
 }
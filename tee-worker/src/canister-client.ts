@@ -7,7 +7,7 @@
  * 
  * Endpoints used:
  * - claim_pending_jobs(limit) → Vec<PendingJob>
- * - submit_job_result(job_id, analysis_json) → ()
+ * - submit_job_result(job_id, analysis_json) → () | SubmitResultError
  * - submit_job_error(job_id, error) → ()
  */
 
@@ -23,12 +23,6 @@ const IC_HOST = 'https://icp0.io';
 // IDL (minimal — only the endpoints we need)
 // ============================================================================
 
-const AnalysisModel = IDL.Variant({
-  Haiku: IDL.Null,
-  Sonnet: IDL.Null,
-  Opus: IDL.Null,
-});
-
 const PendingJobFileIDL = IDL.Record({
   path: IDL.Text,
   content: IDL.Text,
@@ -44,7 +38,11 @@ const PendingJobIDL = IDL.Record({
   skill_md_content: IDL.Opt(IDL.Text),
   skill_files: IDL.Vec(PendingJobFileIDL),
   model: IDL.Text,
+  provider: IDL.Opt(IDL.Text),
+  api_base_url: IDL.Opt(IDL.Text),
   encrypted_api_key: IDL.Text,
+  prompt: IDL.Text,
+  prompt_version: IDL.Opt(IDL.Text),
 });
 
 const ResultText = IDL.Variant({ Ok: IDL.Null, Err: IDL.Text });
@@ -85,13 +83,25 @@ const EnrichmentResultIDL = IDL.Record({
 
 const ResultVecEnrichment = IDL.Variant({ Ok: IDL.Vec(PendingEnrichmentJobIDL), Err: IDL.Text });
 
+const AnalysisViolationIDL = IDL.Record({
+  path: IDL.Text,
+  message: IDL.Text,
+});
+
+const SubmitResultErrorIDL = IDL.Variant({
+  InvalidAnalysis: IDL.Vec(AnalysisViolationIDL),
+  Rejected: IDL.Text,
+});
+
+const ResultSubmit = IDL.Variant({ Ok: IDL.Null, Err: SubmitResultErrorIDL });
+
 const workerIdlFactory = ({ IDL: _IDL }: any) => {
   return IDL.Service({
     claim_pending_jobs: IDL.Func([IDL.Nat32], [ResultVecPending], []),
-    submit_job_result: IDL.Func([IDL.Text, IDL.Text], [ResultText], []),
+    submit_job_result: IDL.Func([IDL.Text, IDL.Text], [ResultSubmit], []),
     submit_job_result_with_metadata: IDL.Func(
       [IDL.Text, IDL.Text, IDL.Text, IDL.Text],
-      [ResultText],
+      [ResultSubmit],
       [],
     ),
     submit_job_error: IDL.Func([IDL.Text, IDL.Text], [ResultText], []),
@@ -122,7 +132,15 @@ export interface PendingJob {
   skill_md_content: string | null;
   skill_files: PendingJobFile[];
   model: string;
+  /** API the model is called through: 'anthropic', 'openai' or 'openai-compatible' */
+  provider: string;
+  /** Endpoint for providers without a fixed one (openai-compatible) */
+  api_base_url: string | null;
   encrypted_api_key: string;
+  /** Analysis prompt, rendered for this skill by the canister */
+  prompt: string;
+  /** Content hash of the prompt revision it was rendered from */
+  prompt_version: string | null;
 }
 
 // ============================================================================
@@ -204,14 +222,36 @@ export async function claimPendingJobs(actor: any, limit: number): Promise<Pendi
       content: f.content,
     })),
     model: j.model,
+    provider: unwrapOpt<string>(j.provider) ?? 'anthropic',
+    api_base_url: unwrapOpt(j.api_base_url),
     encrypted_api_key: j.encrypted_api_key,
+    prompt: j.prompt,
+    prompt_version: unwrapOpt(j.prompt_version),
   }));
 }
 
+/**
+ * The canister refused an analysis that breaks its schema. The job stays
+ * Processing, so the worker can ask the model again and resubmit.
+ */
+export class InvalidAnalysisError extends Error {
+  constructor(readonly violations: string[]) {
+    super(`Invalid analysis: ${violations.join('; ')}`);
+  }
+}
+
+/** Flatten a submit error; invalid analyses list every violation the canister found. */
+function submitError(err: any): Error {
+  if ('InvalidAnalysis' in err) {
+    return new InvalidAnalysisError(err.InvalidAnalysis.map((v: any) => `${v.path || '(root)'}: ${v.message}`));
+  }
+  return new Error(err.Rejected);
+}
+
 export async function submitJobResult(actor: any, jobId: string, analysisJson: string): Promise<void> {
   const result = await actor.submit_job_result(jobId, analysisJson);
   if ('Err' in result) {
-    throw new Error(result.Err);
+    throw submitError(result.Err);
   }
 }
 
@@ -226,7 +266,7 @@ export async function submitJobResultWithMetadata(
     jobId, analysisJson, teeWorkerVersion, promptVersion,
   );
   if ('Err' in result) {
-    throw new Error(result.Err);
+    throw submitError(result.Err);
   }
 }
 
@@ -295,47 +335,3 @@ export async function submitEnrichmentError(actor: any, jobId: string, error: st
     throw new Error(result.Err);
   }
 }
-
-// ============================================================================
-// Prompt fetching (cached)
-// ============================================================================
-
-let cachedPromptTemplate: string | null = null;
-export let cachedPromptVersion: string = '';
-let promptFetchedAt = 0;
-const PROMPT_CACHE_TTL_MS = 5 * 60 * 1000; // Re-fetch every 5 minutes
-
-/**
- * Fetch the default analysis prompt template from the canister.
- * Caches for 5 minutes to avoid excessive queries.
- * THROWS if no prompt is available (no fallback - prompt must come from canister).
- */
-export async function fetchPromptTemplate(actor: any): Promise<string> {
-  const now = Date.now();
-  if (cachedPromptTemplate && (now - promptFetchedAt) < PROMPT_CACHE_TTL_MS) {
-    return cachedPromptTemplate;
-  }
-
-  try {
-    const raw = await actor.get_default_prompt();
-    const result = unwrapOpt(raw) as { prompt_template: string; version: string } | null;
-    if (result && result.prompt_template) {
-      cachedPromptTemplate = result.prompt_template;
-      cachedPromptVersion = result.version || '';
-      promptFetchedAt = now;
-      console.log(`[prompt] Loaded prompt v${result.version} from canister (${cachedPromptTemplate!.length} chars)`);
-      return cachedPromptTemplate;
-    }
-  } catch (e) {
-    console.error('[prompt] Failed to fetch from canister:', e);
-    // Fall through to stale cache check
-  }
-
-  // Return stale cache if available, otherwise throw
-  if (cachedPromptTemplate) {
-    console.warn('[prompt] Using stale cached prompt (canister fetch failed)');
-    return cachedPromptTemplate;
-  }
-
-  throw new Error('No prompt template available. Cannot proceed without canister prompt.');
-}
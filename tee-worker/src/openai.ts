@@ -0,0 +1,70 @@
+/**
+ * OpenAI-compatible API client for the TEE worker.
+ *
+ * Calls the Chat Completions API of OpenAI or any endpoint that speaks it
+ * (self-hosted or third-party), with the decrypted API key.
+ * The key only exists in TEE memory — never logged, never written to disk.
+ */
+
+import type { RawAnalysis } from './types.js';
+import { parseAnalysisJson } from './anthropic.js';
+
+interface ChatMessage {
+  role: 'system' | 'user' | 'assistant';
+  content: string;
+}
+
+interface ChatCompletionRequest {
+  model: string;
+  max_tokens: number;
+  messages: ChatMessage[];
+}
+
+interface ChatCompletionResponse {
+  id: string;
+  choices: { message: { role: string; content: string | null } }[];
+  model: string;
+}
+
+const OPENAI_API_BASE = 'https://api.openai.com/v1';
+
+/**
+ * Call a Chat Completions endpoint and extract the analysis object from the response.
+ * `baseUrl` defaults to OpenAI's API (e.g. "https://llm.example.com/v1" for self-hosted).
+ */
+export async function callOpenAICompatible(
+  apiKey: string,
+  model: string,
+  prompt: string,
+  baseUrl: string | null,
+): Promise<RawAnalysis> {
+  const requestBody: ChatCompletionRequest = {
+    model,
+    max_tokens: 8192,
+    messages: [{ role: 'user', content: prompt }],
+  };
+
+  const url = `${(baseUrl ?? OPENAI_API_BASE).replace(/\/+$/, '')}/chat/completions`;
+  const response = await fetch(url, {
+    method: 'POST',
+    headers: {
+      'authorization': `Bearer ${apiKey}`,
+      'content-type': 'application/json',
+    },
+    body: JSON.stringify(requestBody),
+  });
+
+  if (!response.ok) {
+    const errorBody = await response.text();
+    throw new Error(`OpenAI-compatible API error ${response.status}: ${errorBody}`);
+  }
+
+  const data = (await response.json()) as ChatCompletionResponse;
+
+  const text = data.choices?.[0]?.message?.content;
+  if (!text) {
+    throw new Error('No content in chat completion response');
+  }
+
+  return parseAnalysisJson(text);
+}
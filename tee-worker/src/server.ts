@@ -19,12 +19,12 @@ import { serve } from '@hono/node-server';
 import { DstackClient } from '@phala/dstack-sdk';
 import { decrypt, getPublicKeyHex } from './crypto.js';
 import { callAnthropic } from './anthropic.js';
-import { buildAnalysisPrompt, type SkillData, type AnalysisRequest } from './types.js';
+import { callOpenAICompatible } from './openai.js';
+import type { SkillData, AnalysisRequest } from './types.js';
 import {
   getWorkerActor, getWorkerPrincipal,
-  claimPendingJobs, submitJobResultWithMetadata, submitJobError,
+  claimPendingJobs, submitJobResultWithMetadata, submitJobError, InvalidAnalysisError,
   claimEnrichmentJobs, submitEnrichmentResult, submitEnrichmentError,
-  fetchPromptTemplate, cachedPromptVersion,
   type PendingJob,
   type PendingEnrichmentJob,
   type EnrichmentResult,
@@ -32,6 +32,9 @@ import {
 
 const WORKER_VERSION = '1.9.9';
 
+// Model calls per job: a schema-violating answer is retried with the violations
+const MAX_ANALYSIS_ATTEMPTS = 2;
+
 const app = new Hono();
 
 // Allow the ICP frontend to call /public-key from the browser
@@ -175,8 +178,8 @@ app.post('/analyze', async (c) => {
     if (!body.encrypted_api_key && !body.api_key) {
       return c.json({ error: 'Missing encrypted_api_key or api_key' }, 400);
     }
-    if (!body.skill) {
-      return c.json({ error: 'Missing skill data' }, 400);
+    if (!body.skill?.prompt) {
+      return c.json({ error: 'Missing skill prompt - render it with the canister\'s preview_prompt' }, 400);
     }
 
     // Step 1: Get the API key
@@ -195,13 +198,9 @@ app.post('/analyze', async (c) => {
       return c.json({ error: 'Encrypted API key required in production' }, 400);
     }
 
-    // Step 2: Build prompt and call Anthropic
+    // Step 2: Call Anthropic with the canister-rendered prompt
     const model = body.model || 'claude-haiku-4-5';
-    if (!body.prompt_template) {
-      return c.json({ error: 'Missing prompt_template - required for direct /analyze endpoint' }, 400);
-    }
-    const prompt = buildAnalysisPrompt(body.skill, body.prompt_template);
-    const analysis = await callAnthropic(apiKey, model, prompt);
+    const analysis = await callAnthropic(apiKey, model, body.skill.prompt);
 
     // Step 3: Clear key from memory
     apiKey = '';
@@ -240,7 +239,6 @@ app.post('/analyze-batch', async (c) => {
       api_key?: string;
       skills: SkillData[];
       model?: string;
-      prompt_template?: string;
     }>();
 
     if (!body.skills || body.skills.length === 0) {
@@ -249,8 +247,8 @@ app.post('/analyze-batch', async (c) => {
     if (body.skills.length > 10) {
       return c.json({ error: 'Max 10 skills per batch' }, 400);
     }
-    if (!body.prompt_template) {
-      return c.json({ error: 'Missing prompt_template - required for direct /analyze-batch endpoint' }, 400);
+    if (body.skills.some((skill) => !skill.prompt)) {
+      return c.json({ error: 'Missing skill prompt - render it with the canister\'s preview_prompt' }, 400);
     }
 
     let apiKey: string;
@@ -268,8 +266,7 @@ app.post('/analyze-batch', async (c) => {
 
     for (const skill of body.skills) {
       try {
-        const prompt = buildAnalysisPrompt(skill, body.prompt_template);
-        const analysis = await callAnthropic(apiKey, model, prompt);
+        const analysis = await callAnthropic(apiKey, model, skill.prompt);
         results.push({ skill_id: skill.id, success: true, analysis });
       } catch (error) {
         results.push({
@@ -414,49 +411,55 @@ async function pollJobQueue(): Promise<void> {
 
 async function processJob(job: PendingJob, keyBytes: Uint8Array, actor: any): Promise<void> {
   const startTime = Date.now();
-  console.log(`[job ${job.job_id}] Processing skill ${job.skill_id} with model ${job.model}`);
+  console.log(`[job ${job.job_id}] Processing skill ${job.skill_id} with ${job.provider} model ${job.model}`);
 
   try {
     // Step 1: Decrypt the API key
     const apiKey = decrypt(job.encrypted_api_key, keyBytes);
 
-    // Step 2: Fetch prompt template from canister + build prompt
-    const promptResult = await fetchPromptTemplate(actor);
-    const skillData: SkillData = {
-      id: job.skill_id,
-      name: job.skill_name,
-      description: job.skill_description,
-      owner: job.skill_owner,
-      repo: job.skill_repo,
-      skill_md_content: job.skill_md_content ?? undefined,
-      skill_files: job.skill_files || [],
-    };
-    const prompt = buildAnalysisPrompt(skillData, promptResult);
-    const analysis = await callAnthropic(apiKey, job.model, prompt);
-
-    // Step 2b: Resolve referenced files — check if they exist in skill files
-    if (analysis.referenced_files && analysis.referenced_files.length > 0) {
-      const availableFiles = new Set(
-        (job.skill_files || []).map(f => f.path.toLowerCase())
-      );
-      for (const ref of analysis.referenced_files) {
-        const refPath = ref.path.toLowerCase();
-        ref.resolved = availableFiles.has(refPath)
-          || [...availableFiles].some(p => p.endsWith('/' + refPath) || p.endsWith(refPath));
+    // Step 2: The canister rendered the prompt for this skill when the job was claimed
+    const prompt = job.prompt;
+    const promptVersion = job.prompt_version ?? '';
+
+    let feedback = '';
+    for (let attempt = 1; ; attempt++) {
+      const analysis = job.provider === 'anthropic'
+        ? await callAnthropic(apiKey, job.model, prompt + feedback)
+        : await callOpenAICompatible(apiKey, job.model, prompt + feedback, job.api_base_url);
+
+      // Step 2b: Resolve referenced files — check if they exist in skill files
+      if (Array.isArray(analysis.referenced_files) && analysis.referenced_files.length > 0) {
+        const availableFiles = new Set(
+          (job.skill_files || []).map(f => f.path.toLowerCase())
+        );
+        for (const ref of analysis.referenced_files) {
+          if (typeof ref?.path !== 'string') continue;
+          const refPath = ref.path.toLowerCase();
+          ref.resolved = availableFiles.has(refPath)
+            || [...availableFiles].some(p => p.endsWith('/' + refPath) || p.endsWith(refPath));
+        }
+        const resolved = analysis.referenced_files.filter((f: any) => f?.resolved).length;
+        const total = analysis.referenced_files.length;
+        console.log(`[job ${job.job_id}] Referenced files: ${resolved}/${total} resolved`);
       }
-      const resolved = analysis.referenced_files.filter(f => f.resolved).length;
-      const total = analysis.referenced_files.length;
-      console.log(`[job ${job.job_id}] Referenced files: ${resolved}/${total} resolved`);
-    }
 
-    // Step 3: Submit result back to canister with TEE provenance metadata
-    const analysisJson = JSON.stringify(analysis);
-    // Get prompt version from cached prompt data
-    const promptVersion = cachedPromptVersion || '';
-    await submitJobResultWithMetadata(
-      actor, job.job_id, analysisJson,
-      WORKER_VERSION, promptVersion,
-    );
+      // Step 3: Submit the model's JSON as is; the canister validates it
+      try {
+        await submitJobResultWithMetadata(
+          actor, job.job_id, JSON.stringify(analysis),
+          WORKER_VERSION, promptVersion,
+        );
+        break;
+      } catch (error) {
+        if (!(error instanceof InvalidAnalysisError) || attempt >= MAX_ANALYSIS_ATTEMPTS) {
+          throw error;
+        }
+        console.warn(`[job ${job.job_id}] Attempt ${attempt} rejected: ${error.message}`);
+        feedback = `\n\nYour previous answer was rejected for these schema violations:\n`
+          + error.violations.map(v => `- ${v}`).join('\n')
+          + `\nReply with the corrected JSON object only.`;
+      }
+    }
 
     const elapsed = Date.now() - startTime;
     console.log(`[job ${job.job_id}] Completed in ${elapsed}ms`);
@@ -8,19 +8,9 @@
 // Request types (from ICP canister / frontend)
 // ============================================================================
 
-export interface SkillFile {
-  path: string;
-  content: string;
-}
-
 export interface SkillData {
   id: string;
-  name: string;
-  description: string;
-  owner: string;
-  repo: string;
-  skill_md_content?: string;
-  skill_files?: SkillFile[];
+  prompt: string;              // Analysis prompt rendered by the canister (see preview_prompt)
 }
 
 export interface AnalysisRequest {
@@ -28,7 +18,6 @@ export interface AnalysisRequest {
   api_key?: string;            // Plaintext API key (dev mode only)
   skill: SkillData;
   model?: string;              // Anthropic model ID
-  prompt_template?: string;    // Optional prompt template (required if not using job queue)
 }
 
 // ============================================================================
@@ -103,67 +92,11 @@ export interface SkillAnalysis {
   prerequisites: string[];
   referenced_files: ReferencedFile[];
   referenced_urls: ReferencedUrl[];
-}
-
-// ============================================================================
-// Prompt builder — uses canister prompt template if provided, else hardcoded fallback
-// ============================================================================
-
-/**
- * Build the analysis prompt for a skill.
- * If a promptTemplate is provided (from canister), it uses placeholder substitution.
- * Otherwise falls back to the hardcoded default.
- */
-/**
- * Build a section with all sub-files for the prompt.
- * Truncates individual files to 50KB and total to 200KB.
- */
-function buildFilesSection(files: SkillFile[]): string {
-  if (!files || files.length === 0) return '';
-
-  const MAX_FILE_CHARS = 50_000;
-  const MAX_TOTAL_CHARS = 200_000;
-  let totalChars = 0;
-  const sections: string[] = [];
-
-  for (const file of files) {
-    if (totalChars >= MAX_TOTAL_CHARS) {
-      sections.push(`\n--- (${files.length - sections.length} more files truncated) ---`);
-      break;
-    }
-    const remaining = MAX_TOTAL_CHARS - totalChars;
-    let content = file.content;
-    if (content.length > MAX_FILE_CHARS) {
-      content = content.substring(0, MAX_FILE_CHARS) + '\n... (truncated)';
-    }
-    if (content.length > remaining) {
-      content = content.substring(0, remaining) + '\n... (truncated)';
-    }
-    sections.push(`\n--- FILE: ${file.path} ---\n${content}`);
-    totalChars += content.length;
-  }
-
-  return `\n\nSUB-FILES (${files.length} companion files referenced by SKILL.md):\n${sections.join('\n')}`;
+  embedding?: number[];  // Optional skill embedding for semantic search (one dimension across skills)
 }
 
 /**
- * Build the analysis prompt for a skill.
- * The promptTemplate MUST be loaded from the canister - no fallback.
- * @throws Error if promptTemplate is not provided
+ * An analysis object exactly as the model returned it. The canister validates it
+ * against the analysis schema (SkillAnalysis) and lists any violations.
  */
-export function buildAnalysisPrompt(skill: SkillData, promptTemplate: string): string {
-  if (!promptTemplate) {
-    throw new Error('Prompt template is required - must be loaded from canister');
-  }
-
-  const content = skill.skill_md_content || `# ${skill.name}\n\n${skill.description}`;
-  const filesSection = buildFilesSection(skill.skill_files || []);
-
-  return promptTemplate
-    .replace(/\{owner\}/g, skill.owner)
-    .replace(/\{repo\}/g, skill.repo)
-    .replace(/\{name\}/g, skill.name)
-    .replace(/\{description\}/g, skill.description)
-    .replace(/\{content\}/g, content)
-    .replace(/\{files\}/g, filesSection);
-}
+export type RawAnalysis = Record<string, any>;